@@ -1,4 +1,5 @@
 use std::fs;
+use std::io::Write;
 use std::path::Path;
 use tauri::menu::{MenuBuilder, MenuItemBuilder, SubmenuBuilder};
 use tauri::{AppHandle, Emitter, Manager};
@@ -7,6 +8,58 @@ use serde::{Deserialize, Serialize};
 const MAX_RECENT_FILES: usize = 10;
 const RECENT_FILES_FILENAME: &str = "recent_files.json";
 const SESSION_FILENAME: &str = "session.json";
+const TRASH_DIRNAME: &str = "trash";
+const TRASH_INDEX_FILENAME: &str = "trash_index.json";
+const BLOCKS_DIRNAME: &str = "blocks";
+const VERSION_HISTORY_FILENAME: &str = "version_history.json";
+
+/// Writes `content` to `path` without ever leaving a partially-written file in its place.
+///
+/// The bytes are written to a temporary file in the same directory (so the final step is a
+/// same-filesystem rename), flushed and synced to disk, then moved over the destination with a
+/// single `fs::rename`. If anything fails before the rename, the temporary file is cleaned up and
+/// the original file (if any) is left untouched.
+fn atomic_write(path: &Path, content: &[u8]) -> Result<(), String> {
+    let dir = path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+
+    let existing_permissions = fs::metadata(path).ok().map(|m| m.permissions());
+
+    let tmp_name = format!(
+        ".{}.tmp{}",
+        path.file_name().and_then(|n| n.to_str()).unwrap_or("hone"),
+        std::process::id()
+    );
+    let tmp_path = dir.join(tmp_name);
+
+    let result = (|| -> Result<(), String> {
+        let mut file = fs::File::create(&tmp_path)
+            .map_err(|e| format!("Failed to create temporary file: {}", e))?;
+        file.write_all(content)
+            .map_err(|e| format!("Failed to write temporary file: {}", e))?;
+        file.sync_all()
+            .map_err(|e| format!("Failed to sync temporary file: {}", e))?;
+
+        if let Some(permissions) = &existing_permissions {
+            fs::set_permissions(&tmp_path, permissions.clone())
+                .map_err(|e| format!("Failed to set permissions on temporary file: {}", e))?;
+        }
+
+        Ok(())
+    })();
+
+    if let Err(e) = result {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(e);
+    }
+
+    fs::rename(&tmp_path, path).map_err(|e| {
+        let _ = fs::remove_file(&tmp_path);
+        format!("Failed to finalize file: {}", e)
+    })
+}
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct RecentFile {
@@ -26,6 +79,49 @@ pub struct SessionData {
     pub active_file: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TrashEntry {
+    pub id: String,
+    pub original_path: String,
+    pub trashed_filename: String,
+    pub deleted_at: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+struct TrashIndexData {
+    entries: Vec<TrashEntry>,
+}
+
+fn current_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// One entry in a file's version log. `block_hash` addresses the content in the blocks
+/// directory, so identical saves share storage.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct VersionEntry {
+    path: String,
+    block_hash: String,
+    saved_at: u64,
+    size: u64,
+}
+
+/// A version of a file as returned to the frontend (the `path` is already implied by the query).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Version {
+    pub block_hash: String,
+    pub saved_at: u64,
+    pub size: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+struct VersionHistoryData {
+    entries: Vec<VersionEntry>,
+}
+
 fn get_recent_files_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
     let app_data_dir = app
         .path()
@@ -61,8 +157,7 @@ fn save_recent_files_data(app: &AppHandle, data: &RecentFilesData) -> Result<(),
     let content = serde_json::to_string_pretty(data)
         .map_err(|e| format!("Failed to serialize recent files: {}", e))?;
 
-    fs::write(&path, content)
-        .map_err(|e| format!("Failed to write recent files: {}", e))
+    atomic_write(&path, content.as_bytes())
 }
 
 fn get_session_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
@@ -100,29 +195,739 @@ fn save_session_data(app: &AppHandle, data: &SessionData) -> Result<(), String>
     let content = serde_json::to_string_pretty(data)
         .map_err(|e| format!("Failed to serialize session: {}", e))?;
 
-    fs::write(&path, content)
-        .map_err(|e| format!("Failed to write session: {}", e))
+    atomic_write(&path, content.as_bytes())
+}
+
+fn get_trash_dir(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+
+    let trash_dir = app_data_dir.join(TRASH_DIRNAME);
+    if !trash_dir.exists() {
+        fs::create_dir_all(&trash_dir)
+            .map_err(|e| format!("Failed to create trash directory: {}", e))?;
+    }
+
+    Ok(trash_dir)
+}
+
+fn get_trash_index_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+
+    if !app_data_dir.exists() {
+        fs::create_dir_all(&app_data_dir)
+            .map_err(|e| format!("Failed to create app data directory: {}", e))?;
+    }
+
+    Ok(app_data_dir.join(TRASH_INDEX_FILENAME))
+}
+
+fn load_trash_index(app: &AppHandle) -> Result<TrashIndexData, String> {
+    let path = get_trash_index_path(app)?;
+
+    if !path.exists() {
+        return Ok(TrashIndexData::default());
+    }
+
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read trash index: {}", e))?;
+
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse trash index: {}", e))
+}
+
+fn save_trash_index(app: &AppHandle, data: &TrashIndexData) -> Result<(), String> {
+    let path = get_trash_index_path(app)?;
+
+    let content = serde_json::to_string_pretty(data)
+        .map_err(|e| format!("Failed to serialize trash index: {}", e))?;
+
+    atomic_write(&path, content.as_bytes())
+}
+
+/// Picks a filename under the trash directory that won't collide with an already-trashed file,
+/// even if two files with the same name are deleted in the same session.
+fn unique_trash_filename(trash_dir: &Path, original: &Path) -> String {
+    let stem = original.file_name().and_then(|n| n.to_str()).unwrap_or("file");
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+
+    let mut candidate = format!("{}-{}", nanos, stem);
+    let mut suffix = 0u32;
+    while trash_dir.join(&candidate).exists() {
+        suffix += 1;
+        candidate = format!("{}-{}-{}", nanos, suffix, stem);
+    }
+
+    candidate
+}
+
+fn get_blocks_dir(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+
+    let blocks_dir = app_data_dir.join(BLOCKS_DIRNAME);
+    if !blocks_dir.exists() {
+        fs::create_dir_all(&blocks_dir)
+            .map_err(|e| format!("Failed to create blocks directory: {}", e))?;
+    }
+
+    Ok(blocks_dir)
+}
+
+fn get_version_history_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+
+    if !app_data_dir.exists() {
+        fs::create_dir_all(&app_data_dir)
+            .map_err(|e| format!("Failed to create app data directory: {}", e))?;
+    }
+
+    Ok(app_data_dir.join(VERSION_HISTORY_FILENAME))
+}
+
+fn load_version_history(app: &AppHandle) -> Result<VersionHistoryData, String> {
+    let path = get_version_history_path(app)?;
+
+    if !path.exists() {
+        return Ok(VersionHistoryData::default());
+    }
+
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read version history: {}", e))?;
+
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse version history: {}", e))
+}
+
+fn save_version_history(app: &AppHandle, data: &VersionHistoryData) -> Result<(), String> {
+    let path = get_version_history_path(app)?;
+
+    let content = serde_json::to_string_pretty(data)
+        .map_err(|e| format!("Failed to serialize version history: {}", e))?;
+
+    atomic_write(&path, content.as_bytes())
+}
+
+/// Stores `content` under its BLAKE3 hash (deduplicating against any earlier version with the
+/// same bytes) and appends a log entry for `path`, unless the most recent entry for that path
+/// already points at the same content.
+fn record_snapshot(app: &AppHandle, path: &str, content: &[u8]) -> Result<(), String> {
+    let hash = blake3::hash(content).to_hex().to_string();
+
+    let blocks_dir = get_blocks_dir(app)?;
+    let block_path = blocks_dir.join(&hash);
+    if !block_path.exists() {
+        atomic_write(&block_path, content)?;
+    }
+
+    let mut history = load_version_history(app)?;
+    let already_current = history
+        .entries
+        .iter()
+        .rev()
+        .find(|e| e.path == path)
+        .is_some_and(|e| e.block_hash == hash);
+
+    if !already_current {
+        history.entries.push(VersionEntry {
+            path: path.to_string(),
+            block_hash: hash,
+            saved_at: current_timestamp(),
+            size: content.len() as u64,
+        });
+        save_version_history(app, &history)?;
+    }
+
+    Ok(())
+}
+
+/// Holds the canonicalized root a HONE session may be confined to. `None` means unrestricted
+/// (the default), matching today's behavior of reading and writing anywhere on disk.
+struct WorkspaceRoot(std::sync::Mutex<Option<std::path::PathBuf>>);
+
+/// Resolves `.`, `..`, and redundant separators in `path` purely lexically (no filesystem
+/// access), so it works for paths that don't exist yet.
+fn normalize_path(path: &Path) -> std::path::PathBuf {
+    use std::path::Component;
+
+    let mut result = std::path::PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::ParentDir => {
+                if !result.pop() {
+                    result.push(component.as_os_str());
+                }
+            }
+            Component::CurDir => {}
+            other => result.push(other.as_os_str()),
+        }
+    }
+    result
+}
+
+/// Canonicalizes the deepest existing ancestor of `path` (resolving any symlinks along the
+/// way), then rejoins the remaining, not-yet-existing components. This matters for paths that
+/// don't exist yet but whose parent directory does: without resolving that parent, a symlinked
+/// intermediate directory could be used to escape the workspace root undetected, since the OS
+/// will still follow it when the file is actually created.
+fn canonicalize_through_symlinks(path: &Path) -> std::io::Result<std::path::PathBuf> {
+    let mut existing = path;
+    let mut remainder: Vec<std::ffi::OsString> = Vec::new();
+
+    // `symlink_metadata` rather than `exists()`: a dangling symlink must count as existing so
+    // that it is canonicalized (and rejected) instead of being rejoined unresolved.
+    while fs::symlink_metadata(existing).is_err() {
+        let Some(parent) = existing.parent() else { break };
+        if let Some(name) = existing.file_name() {
+            remainder.push(name.to_os_string());
+        }
+        existing = parent;
+    }
+
+    let mut resolved = fs::canonicalize(existing)?;
+    for component in remainder.into_iter().rev() {
+        resolved.push(component);
+    }
+
+    Ok(resolved)
+}
+
+/// Absolutizes and dedots `path`, then, if a workspace root is set, rejects it unless it
+/// resolves inside that root. The deepest existing ancestor is canonicalized (resolving
+/// symlinks) so a symlink anywhere along the path — including one pointing outside the root for
+/// a file that doesn't exist yet — can't be used to escape it.
+fn resolve_within_workspace(app: &AppHandle, path: &str) -> Result<std::path::PathBuf, String> {
+    let raw = Path::new(path);
+    let absolute = if raw.is_absolute() {
+        raw.to_path_buf()
+    } else {
+        std::env::current_dir()
+            .map_err(|e| format!("Failed to resolve current directory: {}", e))?
+            .join(raw)
+    };
+    let normalized = normalize_path(&absolute);
+
+    let root = app.state::<WorkspaceRoot>().0.lock().unwrap().clone();
+    let Some(root) = root else {
+        return Ok(normalized);
+    };
+
+    if !normalized.starts_with(&root) {
+        return Err(format!("Path escapes workspace root: {}", path));
+    }
+
+    let canonical = canonicalize_through_symlinks(&normalized)
+        .map_err(|e| format!("Failed to resolve path: {}", e))?;
+    if !canonical.starts_with(&root) {
+        return Err(format!("Path escapes workspace root: {}", path));
+    }
+
+    Ok(canonical)
 }
 
 #[tauri::command]
-async fn read_file(path: String) -> Result<String, String> {
-    fs::read_to_string(&path).map_err(|e| format!("Failed to read file: {}", e))
+fn set_workspace_root(app: AppHandle, path: String) -> Result<(), String> {
+    let canonical = fs::canonicalize(&path)
+        .map_err(|e| format!("Failed to resolve workspace root: {}", e))?;
+
+    *app.state::<WorkspaceRoot>().0.lock().unwrap() = Some(canonical);
+
+    Ok(())
 }
 
 #[tauri::command]
-async fn write_file(path: String, content: String) -> Result<(), String> {
-    fs::write(&path, content).map_err(|e| format!("Failed to write file: {}", e))
+async fn read_file(app: AppHandle, path: String) -> Result<String, String> {
+    let resolved = resolve_within_workspace(&app, &path)?;
+    fs::read_to_string(&resolved).map_err(|e| format!("Failed to read file: {}", e))
 }
 
 #[tauri::command]
-fn get_file_dir(path: String) -> Result<String, String> {
-    Path::new(&path)
+async fn write_file(app: AppHandle, path: String, content: String) -> Result<(), String> {
+    let resolved = resolve_within_workspace(&app, &path)?;
+    atomic_write(&resolved, content.as_bytes())?;
+
+    // The file is already durably saved at this point; a failure to record a version snapshot
+    // shouldn't be reported to the frontend as a failed save.
+    if let Err(e) = record_snapshot(&app, &resolved.to_string_lossy(), content.as_bytes()) {
+        eprintln!("Failed to record version snapshot for {}: {}", path, e);
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+fn get_file_dir(app: AppHandle, path: String) -> Result<String, String> {
+    let resolved = resolve_within_workspace(&app, &path)?;
+    resolved
         .parent()
         .and_then(|p| p.to_str())
         .map(|s| s.to_string())
         .ok_or_else(|| "Failed to get directory".to_string())
 }
 
+/// Options shared by `create_dir`/`create_file`: what to do when the target already exists.
+#[derive(Debug, Deserialize, Clone)]
+pub struct CreateOptions {
+    #[serde(default)]
+    pub overwrite: bool,
+    #[serde(default)]
+    pub ignore_if_exists: bool,
+}
+
+/// Options shared by `rename_path`/`copy_file`: what to do when the destination already exists.
+#[derive(Debug, Deserialize, Clone)]
+pub struct PathOpOptions {
+    #[serde(default)]
+    pub overwrite: bool,
+    #[serde(default)]
+    pub ignore_if_exists: bool,
+}
+
+/// Options for `remove_dir`.
+#[derive(Debug, Deserialize, Clone)]
+pub struct RemoveDirOptions {
+    #[serde(default)]
+    pub recursive: bool,
+    #[serde(default)]
+    pub ignore_if_not_exists: bool,
+}
+
+/// Rewrites any recent-files/session entries pointing at `old_path` to `new_path` so renamed
+/// files don't fall out of the recent-files list or the open session.
+fn update_records_on_rename(app: &AppHandle, old_path: &str, new_path: &str) {
+    if let Ok(mut data) = load_recent_files_data(app) {
+        let mut changed = false;
+        for file in data.files.iter_mut() {
+            if file.path == old_path {
+                file.path = new_path.to_string();
+                file.name = Path::new(new_path)
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or(new_path)
+                    .to_string();
+                changed = true;
+            }
+        }
+        if changed {
+            let _ = save_recent_files_data(app, &data);
+        }
+    }
+
+    if let Ok(mut data) = load_session_data(app) {
+        let mut changed = false;
+        for open_path in data.open_files.iter_mut() {
+            if open_path == old_path {
+                *open_path = new_path.to_string();
+                changed = true;
+            }
+        }
+        if data.active_file.as_deref() == Some(old_path) {
+            data.active_file = Some(new_path.to_string());
+            changed = true;
+        }
+        if changed {
+            let _ = save_session_data(app, &data);
+        }
+    }
+
+    // Version history is keyed by absolute path string, so it needs the same rewrite as
+    // recent-files/session or `list_versions`/`prune_versions` silently lose history on rename.
+    if let Ok(mut history) = load_version_history(app) {
+        let dir_prefix = format!("{}/", old_path.trim_end_matches('/'));
+        let mut changed = false;
+        for entry in history.entries.iter_mut() {
+            if entry.path == old_path {
+                entry.path = new_path.to_string();
+                changed = true;
+            } else if let Some(rest) = entry.path.strip_prefix(&dir_prefix) {
+                entry.path = format!("{}/{}", new_path.trim_end_matches('/'), rest);
+                changed = true;
+            }
+        }
+        if changed {
+            let _ = save_version_history(app, &history);
+        }
+    }
+}
+
+/// Drops any recent-files/session entries under `removed_path` (a file or a directory), so
+/// deleted paths don't linger as stale entries.
+fn update_records_on_removal(app: &AppHandle, removed_path: &str) {
+    let dir_prefix = format!("{}/", removed_path.trim_end_matches('/'));
+    let under_removed_path = |p: &str| p == removed_path || p.starts_with(&dir_prefix);
+
+    if let Ok(mut data) = load_recent_files_data(app) {
+        let original_len = data.files.len();
+        data.files.retain(|f| !under_removed_path(&f.path));
+        if data.files.len() != original_len {
+            let _ = save_recent_files_data(app, &data);
+        }
+    }
+
+    if let Ok(mut data) = load_session_data(app) {
+        let original_len = data.open_files.len();
+        data.open_files.retain(|p| !under_removed_path(p));
+        let mut changed = data.open_files.len() != original_len;
+        if let Some(active) = data.active_file.clone() {
+            if under_removed_path(&active) {
+                data.active_file = None;
+                changed = true;
+            }
+        }
+        if changed {
+            let _ = save_session_data(app, &data);
+        }
+    }
+}
+
+#[tauri::command]
+async fn create_dir(app: AppHandle, path: String, options: CreateOptions) -> Result<(), String> {
+    let target = resolve_within_workspace(&app, &path)?;
+
+    if target.exists() {
+        if options.ignore_if_exists {
+            return Ok(());
+        }
+        if !options.overwrite {
+            return Err(format!("Directory already exists: {}", path));
+        }
+        trash_path(&app, &target, &path)?;
+    }
+
+    fs::create_dir_all(&target).map_err(|e| format!("Failed to create directory: {}", e))
+}
+
+#[tauri::command]
+async fn create_file(app: AppHandle, path: String, options: CreateOptions) -> Result<(), String> {
+    let target = resolve_within_workspace(&app, &path)?;
+
+    if target.exists() {
+        if options.ignore_if_exists {
+            return Ok(());
+        }
+        if !options.overwrite {
+            return Err(format!("File already exists: {}", path));
+        }
+        trash_path(&app, &target, &path)?;
+    }
+
+    atomic_write(&target, b"")
+}
+
+#[tauri::command]
+async fn rename_path(
+    app: AppHandle,
+    path: String,
+    to: String,
+    options: PathOpOptions,
+) -> Result<(), String> {
+    let from_path = resolve_within_workspace(&app, &path)?;
+    let to_path = resolve_within_workspace(&app, &to)?;
+
+    if to_path.exists() {
+        if options.ignore_if_exists {
+            return Ok(());
+        }
+        if !options.overwrite {
+            return Err(format!("Target already exists: {}", to));
+        }
+        trash_path(&app, &to_path, &to)?;
+    }
+
+    fs::rename(&from_path, &to_path).map_err(|e| format!("Failed to rename path: {}", e))?;
+
+    update_records_on_rename(&app, &path, &to);
+
+    Ok(())
+}
+
+#[tauri::command]
+async fn copy_file(app: AppHandle, path: String, to: String, options: PathOpOptions) -> Result<(), String> {
+    let from_path = resolve_within_workspace(&app, &path)?;
+    let to_path = resolve_within_workspace(&app, &to)?;
+
+    if to_path.exists() {
+        if options.ignore_if_exists {
+            return Ok(());
+        }
+        if !options.overwrite {
+            return Err(format!("Target already exists: {}", to));
+        }
+        trash_path(&app, &to_path, &to)?;
+    }
+
+    fs::copy(&from_path, &to_path).map_err(|e| format!("Failed to copy file: {}", e))?;
+
+    Ok(())
+}
+
+#[tauri::command]
+async fn remove_file(app: AppHandle, path: String) -> Result<(), String> {
+    trash_file(app, path).await
+}
+
+#[tauri::command]
+async fn remove_dir(app: AppHandle, path: String, options: RemoveDirOptions) -> Result<(), String> {
+    let target = resolve_within_workspace(&app, &path)?;
+
+    if !target.exists() {
+        if options.ignore_if_not_exists {
+            return Ok(());
+        }
+        return Err(format!("Directory does not exist: {}", path));
+    }
+
+    if !options.recursive {
+        let is_empty = fs::read_dir(&target)
+            .map_err(|e| format!("Failed to read directory: {}", e))?
+            .next()
+            .is_none();
+        if !is_empty {
+            return Err(format!("Directory is not empty: {}", path));
+        }
+    }
+
+    trash_path(&app, &target, &path)?;
+
+    update_records_on_removal(&app, &path);
+
+    Ok(())
+}
+
+/// Recursively copies a directory tree, used as the cross-device fallback for `move_path`.
+fn copy_dir_all(from: &Path, to: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(to)?;
+
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let dest = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir_all(&entry.path(), &dest)?;
+        } else {
+            fs::copy(entry.path(), &dest)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Moves `from` to `to`, falling back to a copy-then-remove-original when they're on different
+/// filesystems (`fs::rename` fails with `CrossesDevices` in that case) — the trash directory
+/// lives under the app's data dir, so the file being trashed or restored can easily be on a
+/// different volume.
+fn move_path(from: &Path, to: &Path) -> Result<(), String> {
+    match fs::rename(from, to) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::CrossesDevices => {
+            if from.is_dir() {
+                copy_dir_all(from, to)
+                    .map_err(|e| format!("Failed to copy directory: {}", e))?;
+                fs::remove_dir_all(from)
+                    .map_err(|e| format!("Failed to remove original directory: {}", e))?;
+            } else {
+                fs::copy(from, to).map_err(|e| format!("Failed to copy file: {}", e))?;
+                fs::remove_file(from)
+                    .map_err(|e| format!("Failed to remove original file: {}", e))?;
+            }
+            Ok(())
+        }
+        Err(e) => Err(format!("Failed to move path: {}", e)),
+    }
+}
+
+/// Moves `source` (a file or a directory, already resolved and known to exist) into the trash
+/// and records it under `original_path` so it can be put back exactly where it was. Shared by
+/// every delete path in the app — files, directories, and anything an `overwrite` clobbers —
+/// since any delete capability here should be reversible.
+fn trash_path(app: &AppHandle, source: &Path, original_path: &str) -> Result<(), String> {
+    let trash_dir = get_trash_dir(app)?;
+    let trashed_filename = unique_trash_filename(&trash_dir, source);
+    let trashed_path = trash_dir.join(&trashed_filename);
+
+    move_path(source, &trashed_path)?;
+
+    let mut index = load_trash_index(app)?;
+    index.entries.push(TrashEntry {
+        id: trashed_filename.clone(),
+        original_path: original_path.to_string(),
+        trashed_filename,
+        deleted_at: current_timestamp(),
+    });
+    save_trash_index(app, &index)
+}
+
+/// Moves a file into the trash instead of permanently unlinking it, recording its original
+/// location so it can be put back exactly where it was.
+#[tauri::command]
+async fn trash_file(app: AppHandle, path: String) -> Result<(), String> {
+    let source = resolve_within_workspace(&app, &path)?;
+    if !source.exists() {
+        return Err(format!("File does not exist: {}", path));
+    }
+
+    trash_path(&app, &source, &path)?;
+
+    update_records_on_removal(&app, &path);
+
+    Ok(())
+}
+
+#[tauri::command]
+fn list_trash(app: AppHandle) -> Result<Vec<TrashEntry>, String> {
+    Ok(load_trash_index(&app)?.entries)
+}
+
+#[tauri::command]
+async fn restore_trash(app: AppHandle, id: String) -> Result<(), String> {
+    let mut index = load_trash_index(&app)?;
+    let position = index
+        .entries
+        .iter()
+        .position(|e| e.id == id)
+        .ok_or_else(|| format!("No trash entry found for id: {}", id))?;
+    let entry = index.entries[position].clone();
+
+    let original_path = resolve_within_workspace(&app, &entry.original_path)?;
+    if original_path.exists() {
+        return Err(format!(
+            "Cannot restore: a file already exists at {}",
+            entry.original_path
+        ));
+    }
+
+    if let Some(parent) = original_path.parent() {
+        if !parent.exists() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to recreate parent directory: {}", e))?;
+        }
+    }
+
+    let trash_dir = get_trash_dir(&app)?;
+    let trashed_path = trash_dir.join(&entry.trashed_filename);
+    move_path(&trashed_path, &original_path)?;
+
+    index.entries.remove(position);
+    save_trash_index(&app, &index)?;
+
+    Ok(())
+}
+
+#[tauri::command]
+async fn empty_trash(app: AppHandle) -> Result<(), String> {
+    let trash_dir = get_trash_dir(&app)?;
+    let mut index = load_trash_index(&app)?;
+
+    index.entries.retain(|entry| {
+        let entry_path = trash_dir.join(&entry.trashed_filename);
+        let removed = if entry_path.is_dir() {
+            fs::remove_dir_all(&entry_path)
+        } else {
+            fs::remove_file(&entry_path)
+        };
+        removed.is_err()
+    });
+
+    save_trash_index(&app, &index)
+}
+
+#[tauri::command]
+async fn snapshot_file(app: AppHandle, path: String) -> Result<(), String> {
+    let resolved = resolve_within_workspace(&app, &path)?;
+    let content = fs::read(&resolved).map_err(|e| format!("Failed to read file: {}", e))?;
+    record_snapshot(&app, &resolved.to_string_lossy(), &content)
+}
+
+#[tauri::command]
+fn list_versions(app: AppHandle, path: String) -> Result<Vec<Version>, String> {
+    let resolved = resolve_within_workspace(&app, &path)?;
+    let resolved = resolved.to_string_lossy().to_string();
+
+    let history = load_version_history(&app)?;
+    Ok(history
+        .entries
+        .into_iter()
+        .filter(|e| e.path == resolved)
+        .map(|e| Version {
+            block_hash: e.block_hash,
+            saved_at: e.saved_at,
+            size: e.size,
+        })
+        .collect())
+}
+
+#[tauri::command]
+async fn restore_version(app: AppHandle, path: String, block_hash: String) -> Result<(), String> {
+    if !is_valid_block_hash(&block_hash) {
+        return Err(format!("Invalid block hash: {}", block_hash));
+    }
+
+    let resolved = resolve_within_workspace(&app, &path)?;
+
+    let blocks_dir = get_blocks_dir(&app)?;
+    let content = fs::read(blocks_dir.join(&block_hash))
+        .map_err(|e| format!("Failed to read stored version: {}", e))?;
+
+    atomic_write(&resolved, &content)?;
+    record_snapshot(&app, &resolved.to_string_lossy(), &content)
+}
+
+/// A BLAKE3 hex digest is exactly 64 lowercase hex characters; rejecting anything else keeps a
+/// caller-supplied `block_hash` from being usable as a path (e.g. `../../etc/passwd`) when it's
+/// joined onto the blocks directory.
+fn is_valid_block_hash(hash: &str) -> bool {
+    hash.len() == 64 && hash.bytes().all(|b| b.is_ascii_hexdigit() && !b.is_ascii_uppercase())
+}
+
+#[tauri::command]
+fn prune_versions(app: AppHandle, path: String, keep_last: usize) -> Result<(), String> {
+    let resolved = resolve_within_workspace(&app, &path)?;
+    let resolved = resolved.to_string_lossy().to_string();
+
+    let mut history = load_version_history(&app)?;
+
+    let matching: usize = history.entries.iter().filter(|e| e.path == resolved).count();
+    if matching > keep_last {
+        let mut drop_remaining = matching - keep_last;
+        history.entries.retain(|e| {
+            if e.path == resolved && drop_remaining > 0 {
+                drop_remaining -= 1;
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    save_version_history(&app, &history)?;
+
+    // Garbage-collect blocks no longer referenced by any log entry.
+    let referenced: std::collections::HashSet<&str> =
+        history.entries.iter().map(|e| e.block_hash.as_str()).collect();
+    let blocks_dir = get_blocks_dir(&app)?;
+    if let Ok(entries) = fs::read_dir(&blocks_dir) {
+        for entry in entries.flatten() {
+            if let Some(name) = entry.file_name().to_str() {
+                if !referenced.contains(name) {
+                    let _ = fs::remove_file(entry.path());
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[tauri::command]
 fn get_recent_files(app: AppHandle) -> Result<Vec<RecentFile>, String> {
     let mut data = load_recent_files_data(&app)?;
@@ -201,12 +1006,114 @@ fn get_session(app: AppHandle) -> Result<SessionData, String> {
 }
 
 #[tauri::command]
-fn save_session(app: AppHandle, open_files: Vec<String>, active_file: Option<String>) -> Result<(), String> {
+fn save_session(
+    app: AppHandle,
+    watcher: tauri::State<FileWatcher>,
+    open_files: Vec<String>,
+    active_file: Option<String>,
+) -> Result<(), String> {
     let data = SessionData {
         open_files,
         active_file,
     };
-    save_session_data(&app, &data)
+    save_session_data(&app, &data)?;
+    watcher.sync_watched_paths(&data.open_files);
+    Ok(())
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct FileChangedPayload {
+    path: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct FileRemovedPayload {
+    path: String,
+}
+
+/// Watches every file the session currently has open and emits `file-changed`/`file-removed`
+/// events when one is modified, moved, or deleted outside of HONE (e.g. by git or another
+/// editor). Rapid bursts of filesystem events for the same path are coalesced into a single
+/// event, since editors and sync tools routinely produce several for one logical save.
+struct FileWatcher {
+    watcher: std::sync::Mutex<notify::RecommendedWatcher>,
+    watched: std::sync::Mutex<std::collections::HashSet<String>>,
+}
+
+impl FileWatcher {
+    const DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(200);
+
+    fn new(app: &AppHandle) -> notify::Result<Self> {
+        let pending: std::sync::Arc<std::sync::Mutex<std::collections::HashMap<std::path::PathBuf, std::time::Instant>>> =
+            std::sync::Arc::new(std::sync::Mutex::new(std::collections::HashMap::new()));
+
+        let debounce_pending = pending.clone();
+        let debounce_app = app.clone();
+        std::thread::spawn(move || loop {
+            std::thread::sleep(std::time::Duration::from_millis(50));
+
+            let mut due = Vec::new();
+            {
+                let mut map = debounce_pending.lock().unwrap();
+                let now = std::time::Instant::now();
+                map.retain(|path, seen| {
+                    if now.duration_since(*seen) >= Self::DEBOUNCE {
+                        due.push(path.clone());
+                        false
+                    } else {
+                        true
+                    }
+                });
+            }
+
+            for path in due {
+                let path_str = path.to_string_lossy().to_string();
+                if path.exists() {
+                    let _ = debounce_app.emit("file-changed", FileChangedPayload { path: path_str });
+                } else {
+                    let _ = debounce_app.emit("file-removed", FileRemovedPayload { path: path_str });
+                }
+            }
+        });
+
+        let watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            let Ok(event) = res else { return };
+            match event.kind {
+                notify::EventKind::Modify(_) | notify::EventKind::Create(_) | notify::EventKind::Remove(_) => {
+                    let mut map = pending.lock().unwrap();
+                    let now = std::time::Instant::now();
+                    for path in event.paths {
+                        map.insert(path, now);
+                    }
+                }
+                _ => {}
+            }
+        })?;
+
+        Ok(Self {
+            watcher: std::sync::Mutex::new(watcher),
+            watched: std::sync::Mutex::new(std::collections::HashSet::new()),
+        })
+    }
+
+    /// Starts watching newly opened files and stops watching ones that were closed, so the
+    /// watch set always matches `SessionData.open_files`.
+    fn sync_watched_paths(&self, open_files: &[String]) {
+        use notify::Watcher;
+
+        let new_set: std::collections::HashSet<String> = open_files.iter().cloned().collect();
+        let mut watched = self.watched.lock().unwrap();
+        let mut watcher = self.watcher.lock().unwrap();
+
+        for path in watched.difference(&new_set) {
+            let _ = watcher.unwatch(Path::new(path));
+        }
+        for path in new_set.difference(&watched) {
+            let _ = watcher.watch(Path::new(path), notify::RecursiveMode::NonRecursive);
+        }
+
+        *watched = new_set;
+    }
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -215,6 +1122,14 @@ pub fn run() {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
         .setup(|app| {
+            app.manage(WorkspaceRoot(std::sync::Mutex::new(None)));
+
+            let file_watcher = FileWatcher::new(app.handle())?;
+            if let Ok(session) = load_session_data(app.handle()) {
+                file_watcher.sync_watched_paths(&session.open_files);
+            }
+            app.manage(file_watcher);
+
             // Create menu items with keyboard shortcuts
             let open = MenuItemBuilder::with_id("open", "Open")
                 .accelerator("CmdOrCtrl+O")
@@ -291,7 +1206,30 @@ pub fn run() {
 
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![read_file, write_file, get_file_dir, get_recent_files, add_recent_file, get_session, save_session])
+        .invoke_handler(tauri::generate_handler![
+            set_workspace_root,
+            read_file,
+            write_file,
+            get_file_dir,
+            create_dir,
+            create_file,
+            rename_path,
+            copy_file,
+            remove_file,
+            remove_dir,
+            trash_file,
+            list_trash,
+            restore_trash,
+            empty_trash,
+            snapshot_file,
+            list_versions,
+            restore_version,
+            prune_versions,
+            get_recent_files,
+            add_recent_file,
+            get_session,
+            save_session
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }